@@ -1,10 +1,19 @@
 mod audio;
+mod midi;
 mod music_theory;
 
 use audio::{generate_progression_samples, SAMPLE_RATE};
+use midi::{render_midi, MidiConfig, StrumMode};
 use music_theory::{generate_modal_progression, Mode, Note};
 use wasm_bindgen::prelude::*;
 
+// Re-export the music-theory surface built out across the backlog so the
+// additions are reachable from the crate API (and don't read as dead code).
+pub use music_theory::{
+    generate_modal_progression_7th, generate_scale_progression, identify_chords, spell_scale,
+    Chord, ChordTab, Pitch, Scale, SpelledNote, VoicingConfig,
+};
+
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen(js_namespace = console)]
@@ -192,6 +201,37 @@ impl JamTrackGenerator {
             .map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))
     }
 
+    /// Render the progression to a standard MIDI file as raw bytes.
+    ///
+    /// `strum` selects the playback feel ("block", "up", or "broken") and
+    /// `program` is the General MIDI instrument number.
+    #[wasm_bindgen]
+    pub fn generate_midi(&self, strum: String, program: u8) -> Result<Vec<u8>, JsValue> {
+        let root = Note::from_string(&self.config.key)
+            .ok_or_else(|| JsValue::from_str(&format!("Invalid key: {}", self.config.key)))?;
+
+        let mode = Mode::from_string(&self.config.mode)
+            .ok_or_else(|| JsValue::from_str(&format!("Invalid mode: {}", self.config.mode)))?;
+
+        let progression = generate_modal_progression(root, mode);
+
+        let strum = match strum.to_lowercase().as_str() {
+            "up" | "up-arpeggio" | "arpeggio" => StrumMode::UpArpeggio,
+            "broken" => StrumMode::Broken,
+            _ => StrumMode::Block,
+        };
+
+        let midi_config = MidiConfig {
+            tempo: self.config.tempo,
+            octave: self.config.octave,
+            beats_per_chord: self.config.beats_per_chord,
+            strum,
+            program,
+        };
+
+        Ok(render_midi(&progression, &midi_config))
+    }
+
     /// Update the configuration
     #[wasm_bindgen]
     pub fn update_config(&mut self, config: JamTrackConfig) {
@@ -0,0 +1,175 @@
+use crate::music_theory::Chord;
+use midly::num::{u15, u24, u28, u4, u7};
+use midly::{Format, Header, MetaMessage, MidiMessage, Smf, Timing, Track, TrackEvent, TrackEventKind};
+
+/// Pulses per quarter note used for the rendered MIDI file.
+const TICKS_PER_BEAT: u16 = 480;
+
+/// How the notes of each chord are triggered in time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StrumMode {
+    /// All notes struck together and held for the full duration.
+    Block,
+    /// Notes entered low-to-high, each held until the end of the chord.
+    UpArpeggio,
+    /// Notes played one after another, each released before the next.
+    Broken,
+}
+
+/// Settings for rendering a progression to MIDI.
+#[derive(Debug, Clone, Copy)]
+pub struct MidiConfig {
+    /// Tempo in beats per minute.
+    pub tempo: f64,
+    /// Octave the chord roots are voiced in (`C4` is middle C).
+    pub octave: u8,
+    /// Beats each chord sounds for.
+    pub beats_per_chord: f64,
+    /// How the chord is strummed/arpeggiated.
+    pub strum: StrumMode,
+    /// General MIDI program (instrument) number, 0-127.
+    pub program: u8,
+}
+
+impl Default for MidiConfig {
+    fn default() -> Self {
+        MidiConfig {
+            tempo: 120.0,
+            octave: 3,
+            beats_per_chord: 4.0,
+            strum: StrumMode::Block,
+            program: 0,
+        }
+    }
+}
+
+/// A single note-on or note-off at an absolute tick.
+struct NoteEvent {
+    tick: u32,
+    on: bool,
+    key: u8,
+}
+
+/// Map a chord note to a MIDI key number in the given octave.
+fn midi_key(semitone: u8, octave: u8) -> u8 {
+    ((semitone as i32) + (octave as i32 + 1) * 12).clamp(0, 127) as u8
+}
+
+/// Build the absolute note events for one chord starting at `start` ticks.
+fn chord_events(chord: &Chord, start: u32, duration: u32, octave: u8, strum: StrumMode) -> Vec<NoteEvent> {
+    let mut keys: Vec<u8> = chord
+        .notes()
+        .iter()
+        .map(|note| midi_key(note.semitone(), octave))
+        .collect();
+    if strum == StrumMode::UpArpeggio {
+        keys.sort_unstable();
+    }
+    let n = keys.len().max(1) as u32;
+    let mut events = Vec::new();
+
+    for (i, &key) in keys.iter().enumerate() {
+        let i = i as u32;
+        let (on, off) = match strum {
+            StrumMode::Block => (start, start + duration),
+            StrumMode::UpArpeggio => (start + i * duration / n, start + duration),
+            StrumMode::Broken => (start + i * duration / n, start + (i + 1) * duration / n),
+        };
+        events.push(NoteEvent { tick: on, on: true, key });
+        events.push(NoteEvent { tick: off, on: false, key });
+    }
+
+    events
+}
+
+/// Render a chord progression to a standard MIDI file, returned as raw bytes.
+pub fn render_midi(chords: &[Chord], config: &MidiConfig) -> Vec<u8> {
+    let duration = (config.beats_per_chord * TICKS_PER_BEAT as f64) as u32;
+
+    // Collect every note event at its absolute tick.
+    let mut events = Vec::new();
+    for (i, chord) in chords.iter().enumerate() {
+        let start = i as u32 * duration;
+        events.extend(chord_events(chord, start, duration, config.octave, config.strum));
+    }
+    // Order by tick, releasing notes before striking new ones at the same tick.
+    events.sort_by(|a, b| a.tick.cmp(&b.tick).then(a.on.cmp(&b.on)));
+
+    let mut track: Track = Vec::new();
+
+    // Tempo and instrument are set up at the head of the track.
+    let micros_per_beat = (60_000_000.0 / config.tempo) as u32;
+    track.push(TrackEvent {
+        delta: u28::new(0),
+        kind: TrackEventKind::Meta(MetaMessage::Tempo(u24::new(micros_per_beat))),
+    });
+    track.push(TrackEvent {
+        delta: u28::new(0),
+        kind: TrackEventKind::Midi {
+            channel: u4::new(0),
+            message: MidiMessage::ProgramChange {
+                program: u7::new(config.program.min(127)),
+            },
+        },
+    });
+
+    let mut prev_tick = 0u32;
+    for event in events {
+        let delta = event.tick - prev_tick;
+        prev_tick = event.tick;
+        let message = if event.on {
+            MidiMessage::NoteOn {
+                key: u7::new(event.key),
+                vel: u7::new(80),
+            }
+        } else {
+            MidiMessage::NoteOff {
+                key: u7::new(event.key),
+                vel: u7::new(0),
+            }
+        };
+        track.push(TrackEvent {
+            delta: u28::new(delta),
+            kind: TrackEventKind::Midi {
+                channel: u4::new(0),
+                message,
+            },
+        });
+    }
+
+    track.push(TrackEvent {
+        delta: u28::new(0),
+        kind: TrackEventKind::Meta(MetaMessage::EndOfTrack),
+    });
+
+    let mut smf = Smf::new(Header {
+        format: Format::SingleTrack,
+        timing: Timing::Metrical(u15::new(TICKS_PER_BEAT)),
+    });
+    smf.tracks.push(track);
+
+    let mut bytes = Vec::new();
+    smf.write(&mut bytes).expect("writing MIDI to an in-memory buffer cannot fail");
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::music_theory::{Chord, Note};
+
+    #[test]
+    fn test_midi_key_mapping() {
+        // Middle C (C4) is MIDI key 60.
+        assert_eq!(midi_key(Note::C.semitone(), 4), 60);
+        assert_eq!(midi_key(Note::A.semitone(), 4), 69);
+    }
+
+    #[test]
+    fn test_render_midi_header() {
+        let chords = vec![Chord::major(Note::C), Chord::major(Note::G)];
+        let bytes = render_midi(&chords, &MidiConfig::default());
+        // A valid SMF starts with the "MThd" chunk magic.
+        assert_eq!(&bytes[0..4], b"MThd");
+    }
+}
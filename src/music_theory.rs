@@ -96,6 +96,198 @@ impl fmt::Display for Note {
     }
 }
 
+/// A specific sounding pitch: a note fixed in an octave, e.g. `C4` (middle C).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Pitch {
+    pub note: Note,
+    pub octave: u8,
+}
+
+impl Pitch {
+    /// Create a new pitch.
+    pub fn new(note: Note, octave: u8) -> Self {
+        Pitch { note, octave }
+    }
+
+    /// Parse scientific pitch notation such as `"C#4"` or `"Gb2"`.
+    pub fn from_string(s: &str) -> Option<Self> {
+        let split = s.find(|c: char| c.is_ascii_digit())?;
+        let (name, octave) = s.split_at(split);
+        Some(Pitch {
+            note: Note::from_string(name)?,
+            octave: octave.parse().ok()?,
+        })
+    }
+
+    /// Get the frequency of this pitch (A4 = 440Hz).
+    pub fn frequency(&self) -> f64 {
+        self.note.frequency(self.octave)
+    }
+
+    /// MIDI key number for this pitch (C4 = 60).
+    pub fn midi_number(&self) -> u8 {
+        (self.note.semitone() as i32 + (self.octave as i32 + 1) * 12).clamp(0, 127) as u8
+    }
+
+    /// Transpose by a number of semitones, rolling over octave boundaries.
+    pub fn transpose(&self, semitones: i32) -> Pitch {
+        // Clamp to a floor of MIDI 12 (C0) so the octave stays representable as
+        // a `u8` — below that the scientific octave would go negative.
+        let absolute = (self.midi_number() as i32 + semitones).clamp(12, 127);
+        Pitch {
+            note: Note::from_semitone((absolute % 12) as u8),
+            octave: (absolute / 12 - 1) as u8,
+        }
+    }
+}
+
+impl fmt::Display for Pitch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.note, self.octave)
+    }
+}
+
+/// A note spelled with an explicit letter and accidental, so enharmonic
+/// distinctions like `Db` vs `C#` survive display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpelledNote {
+    pub letter: char,
+    /// Accidental in semitones, -2..=2 (double-flat to double-sharp).
+    pub accidental: i8,
+}
+
+impl SpelledNote {
+    /// The pitch class (0-11) this spelling sounds.
+    pub fn semitone(&self) -> u8 {
+        (letter_semitone(self.letter) as i32 + self.accidental as i32).rem_euclid(12) as u8
+    }
+}
+
+impl fmt::Display for SpelledNote {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let accidental = match self.accidental {
+            -2 => "bb",
+            -1 => "b",
+            0 => "",
+            1 => "#",
+            2 => "##",
+            _ => "?",
+        };
+        write!(f, "{}{}", self.letter, accidental)
+    }
+}
+
+/// Semitone of a natural letter A-G.
+fn letter_semitone(letter: char) -> u8 {
+    match letter {
+        'C' => 0,
+        'D' => 2,
+        'E' => 4,
+        'F' => 5,
+        'G' => 7,
+        'A' => 9,
+        'B' => 11,
+        _ => 0,
+    }
+}
+
+/// The next letter name, wrapping G back to A.
+fn next_letter(letter: char) -> char {
+    match letter {
+        'A' => 'B',
+        'B' => 'C',
+        'C' => 'D',
+        'D' => 'E',
+        'E' => 'F',
+        'F' => 'G',
+        _ => 'A',
+    }
+}
+
+/// Number of letter names a chromatic interval spans above the root (a third
+/// is two letters, a fifth four, a seventh six). Nine semitones is ambiguous — a
+/// sixth chord spells it as a sixth, a diminished seventh as a seventh — so
+/// the presence of a perfect fifth disambiguates.
+fn interval_letter_steps(interval: u8, has_perfect_fifth: bool) -> usize {
+    match interval % 12 {
+        0 => 0,
+        1 | 2 => 1,
+        3 | 4 => 2,
+        5 => 3,
+        6 | 7 | 8 => 4,
+        9 => {
+            if has_perfect_fifth {
+                5
+            } else {
+                6
+            }
+        }
+        _ => 6,
+    }
+}
+
+/// Whether a root is conventionally spelled with flats (F, Bb, Eb, Ab, Db).
+fn prefers_flats(root: Note) -> bool {
+    matches!(root.semitone(), 1 | 3 | 5 | 8 | 10)
+}
+
+/// Spell a single pitch class, choosing sharps or flats by key preference.
+fn spell_pitch_class(pc: u8, prefer_flats: bool) -> SpelledNote {
+    let (letter, accidental) = if prefer_flats {
+        match pc % 12 {
+            0 => ('C', 0),
+            1 => ('D', -1),
+            2 => ('D', 0),
+            3 => ('E', -1),
+            4 => ('E', 0),
+            5 => ('F', 0),
+            6 => ('G', -1),
+            7 => ('G', 0),
+            8 => ('A', -1),
+            9 => ('A', 0),
+            10 => ('B', -1),
+            _ => ('B', 0),
+        }
+    } else {
+        match pc % 12 {
+            0 => ('C', 0),
+            1 => ('C', 1),
+            2 => ('D', 0),
+            3 => ('D', 1),
+            4 => ('E', 0),
+            5 => ('F', 0),
+            6 => ('F', 1),
+            7 => ('G', 0),
+            8 => ('G', 1),
+            9 => ('A', 0),
+            10 => ('A', 1),
+            _ => ('B', 0),
+        }
+    };
+    SpelledNote { letter, accidental }
+}
+
+/// Spell the seven degrees of a mode rooted on `root` using consecutive letter
+/// names, so each letter appears exactly once and accidentals read correctly
+/// (e.g. `F Ionian` gives `F G A Bb C D E`, not `F G A A# C D E`).
+pub fn spell_scale(root: Note, mode: Mode) -> Vec<SpelledNote> {
+    let prefer_flats = prefers_flats(root);
+    let mut letter = spell_pitch_class(root.semitone(), prefer_flats).letter;
+
+    mode.intervals()
+        .iter()
+        .map(|&interval| {
+            let target = (root.semitone() + interval) % 12;
+            let raw = target as i32 - letter_semitone(letter) as i32;
+            // Pick the accidental with the smallest magnitude for this letter.
+            let accidental = (((raw + 6).rem_euclid(12)) - 6) as i8;
+            let spelled = SpelledNote { letter, accidental };
+            letter = next_letter(letter);
+            spelled
+        })
+        .collect()
+}
+
 /// Musical modes with their interval patterns
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Mode {
@@ -160,6 +352,147 @@ impl fmt::Display for Mode {
     }
 }
 
+/// A scale built from an arbitrary interval pattern, covering the church modes
+/// as well as presets (harmonic/melodic minor, pentatonics, blues, whole tone)
+/// and user-supplied step patterns.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Scale {
+    pub name: String,
+    /// Ascending semitone offsets from the root, starting at 0.
+    pub intervals: Vec<u8>,
+}
+
+impl Scale {
+    /// Create a scale from a name and its semitone offsets.
+    pub fn new(name: impl Into<String>, intervals: Vec<u8>) -> Self {
+        Scale {
+            name: name.into(),
+            intervals,
+        }
+    }
+
+    /// Harmonic minor scale.
+    pub fn harmonic_minor() -> Self {
+        Scale::new("Harmonic Minor", vec![0, 2, 3, 5, 7, 8, 11])
+    }
+
+    /// Melodic minor (ascending) scale.
+    pub fn melodic_minor() -> Self {
+        Scale::new("Melodic Minor", vec![0, 2, 3, 5, 7, 9, 11])
+    }
+
+    /// Major pentatonic scale.
+    pub fn major_pentatonic() -> Self {
+        Scale::new("Major Pentatonic", vec![0, 2, 4, 7, 9])
+    }
+
+    /// Minor pentatonic scale.
+    pub fn minor_pentatonic() -> Self {
+        Scale::new("Minor Pentatonic", vec![0, 3, 5, 7, 10])
+    }
+
+    /// Blues scale.
+    pub fn blues() -> Self {
+        Scale::new("Blues", vec![0, 3, 5, 6, 7, 10])
+    }
+
+    /// Whole-tone scale.
+    pub fn whole_tone() -> Self {
+        Scale::new("Whole Tone", vec![0, 2, 4, 6, 8, 10])
+    }
+
+    /// Look up a preset scale by name.
+    pub fn preset(name: &str) -> Option<Self> {
+        match name.to_lowercase().replace([' ', '-', '_'], "").as_str() {
+            "harmonicminor" => Some(Scale::harmonic_minor()),
+            "melodicminor" => Some(Scale::melodic_minor()),
+            "majorpentatonic" | "pentatonic" => Some(Scale::major_pentatonic()),
+            "minorpentatonic" => Some(Scale::minor_pentatonic()),
+            "blues" => Some(Scale::blues()),
+            "wholetone" => Some(Scale::whole_tone()),
+            _ => None,
+        }
+    }
+
+    /// Build a scale from a step pattern, where `W`/`M` is a whole step and
+    /// `H`/`m` a half step (e.g. `"WWHWWWH"` or `"M-m-M-M-M"`). Separators
+    /// (`-` or spaces) are ignored.
+    pub fn from_steps(pattern: &str) -> Option<Self> {
+        let mut intervals = vec![0u8];
+        let mut offset = 0u8;
+        for c in pattern.chars() {
+            let step = match c {
+                'W' | 'w' | 'M' => 2,
+                'H' | 'h' | 'm' => 1,
+                '-' | ' ' => continue,
+                _ => return None,
+            };
+            offset += step;
+            if offset < 12 {
+                intervals.push(offset);
+            }
+        }
+        Some(Scale::new(format!("Custom ({})", pattern), intervals))
+    }
+
+    /// Bridge an existing [`Mode`] into a [`Scale`].
+    pub fn from_mode(mode: Mode) -> Self {
+        Scale::new(mode.to_string(), mode.intervals())
+    }
+
+    /// Semitone offset of the `n`th degree, continuing past the top of the
+    /// scale into the next octave so thirds can be stacked freely.
+    pub fn degree(&self, n: usize) -> u8 {
+        let len = self.intervals.len();
+        self.intervals[n % len] + 12 * (n / len) as u8
+    }
+
+    /// The notes of this scale rooted on `root`.
+    pub fn notes(&self, root: Note) -> Vec<Note> {
+        self.intervals
+            .iter()
+            .map(|&interval| root.transpose(interval as i32))
+            .collect()
+    }
+
+    /// Harmonize one scale degree into a chord by stacking scale-internal
+    /// thirds (degree, degree+2, degree+4).
+    fn harmonize(&self, root: Note, degree: usize) -> Chord {
+        let base = self.degree(degree) as i32;
+        let chord_root = root.transpose(base);
+        let mut intervals: Vec<u8> = [0, 2, 4]
+            .iter()
+            .map(|&step| (self.degree(degree + step) as i32 - base).rem_euclid(12) as u8)
+            .collect();
+        intervals.sort_unstable();
+        intervals.dedup();
+        let name = name_for_intervals(chord_root, &intervals);
+        Chord::new(chord_root, intervals, name)
+    }
+}
+
+/// Name the chord with the given root and interval set, falling back to the
+/// bare root name when no template matches.
+fn name_for_intervals(root: Note, intervals: &[u8]) -> String {
+    for (template, suffix) in chord_templates() {
+        if template.len() == intervals.len() && template.iter().all(|t| intervals.contains(t)) {
+            return format!("{}{}", root, suffix);
+        }
+    }
+    format!("{}", root)
+}
+
+/// Generate a chord progression over an arbitrary [`Scale`], harmonizing each
+/// degree by stacking scale-internal thirds so non-heptatonic scales degrade
+/// gracefully.
+pub fn generate_scale_progression(root: Note, scale: &Scale) -> Vec<Chord> {
+    let len = scale.intervals.len();
+    [0usize, 3, 0, 4]
+        .iter()
+        .map(|&degree| scale.harmonize(root, degree % len))
+        .collect()
+}
+
 /// Represents a chord
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Chord {
@@ -186,6 +519,47 @@ impl Chord {
             .collect()
     }
 
+    /// Voice the chord as ascending pitches starting in `base_octave`, stacking
+    /// each interval across octave boundaries instead of wrapping to pitch
+    /// classes, so a chord reads as rising pitches rather than collapsing into
+    /// a single octave.
+    pub fn voiced_notes(&self, base_octave: u8) -> Vec<Pitch> {
+        let base = Pitch::new(self.root, base_octave);
+        self.intervals
+            .iter()
+            .map(|&interval| base.transpose(interval as i32))
+            .collect()
+    }
+
+    /// Get the chord's notes spelled with letters and accidentals. The root is
+    /// spelled by the flat/sharp key convention for that root, so a chord
+    /// rooted on Db reads `Db` rather than `C#`.
+    ///
+    /// Like [`spell_scale`], this walks letter names outward from the root —
+    /// each chord tone is assigned the letter its generic interval demands
+    /// (a third is two letters up, a fifth four, a seventh six) — so a minor
+    /// chord reads `C Eb G` rather than the enharmonic `C D# G`, and letter
+    /// names never repeat.
+    pub fn spelled_notes(&self) -> Vec<SpelledNote> {
+        let prefer_flats = prefers_flats(self.root);
+        let root_letter = spell_pitch_class(self.root.semitone(), prefer_flats).letter;
+        let has_perfect_fifth = self.intervals.contains(&7);
+        self.intervals
+            .iter()
+            .map(|&interval| {
+                let steps = interval_letter_steps(interval, has_perfect_fifth);
+                let mut letter = root_letter;
+                for _ in 0..steps {
+                    letter = next_letter(letter);
+                }
+                let target = (self.root.semitone() + interval) % 12;
+                let raw = target as i32 - letter_semitone(letter) as i32;
+                let accidental = (((raw + 6).rem_euclid(12)) - 6) as i8;
+                SpelledNote { letter, accidental }
+            })
+            .collect()
+    }
+
     /// Get frequencies for all notes in the chord at a given octave
     pub fn frequencies(&self, octave: u8) -> Vec<f64> {
         self.notes()
@@ -224,9 +598,58 @@ impl Chord {
         Chord::new(root, vec![0, 7], format!("{}5", root))
     }
 
+    /// Create a major seventh chord
+    pub fn major_seventh(root: Note) -> Self {
+        Chord::new(root, vec![0, 4, 7, 11], format!("{}maj7", root))
+    }
+
+    /// Create a minor seventh chord
+    pub fn minor_seventh(root: Note) -> Self {
+        Chord::new(root, vec![0, 3, 7, 10], format!("{}m7", root))
+    }
+
+    /// Create a dominant seventh chord
+    pub fn dominant_seventh(root: Note) -> Self {
+        Chord::new(root, vec![0, 4, 7, 10], format!("{}7", root))
+    }
+
+    /// Create a half-diminished (m7b5) chord
+    pub fn half_diminished(root: Note) -> Self {
+        Chord::new(root, vec![0, 3, 6, 10], format!("{}m7b5", root))
+    }
+
+    /// Create a fully diminished seventh chord
+    pub fn diminished_seventh(root: Note) -> Self {
+        Chord::new(root, vec![0, 3, 6, 9], format!("{}dim7", root))
+    }
+
+    /// Create a major sixth chord
+    pub fn major_sixth(root: Note) -> Self {
+        Chord::new(root, vec![0, 4, 7, 9], format!("{}6", root))
+    }
+
+    /// Create a minor sixth chord
+    pub fn minor_sixth(root: Note) -> Self {
+        Chord::new(root, vec![0, 3, 7, 9], format!("{}m6", root))
+    }
+
+    /// Create an augmented chord
+    pub fn augmented(root: Note) -> Self {
+        Chord::new(root, vec![0, 4, 8], format!("{}aug", root))
+    }
+
     /// Get guitar chord tab/fingering for this chord
+    ///
+    /// Uses the curated fingerings of the static table when one exists for this chord
+    /// name, otherwise falls back to the algorithmic voicing search so extended
+    /// and high-position chords still produce a playable tab.
     pub fn get_guitar_tab(&self) -> Option<ChordTab> {
-        get_chord_tab(&self.name)
+        get_chord_tab(&self.name).or_else(|| {
+            self.best_voicing(VoicingConfig::default()).map(|mut tab| {
+                tab.name = self.name.clone();
+                tab
+            })
+        })
     }
 }
 
@@ -252,6 +675,257 @@ impl ChordTab {
     }
 }
 
+/// Interval templates for the chord qualities this crate can build, paired with
+/// the name suffix appended to the root. Ordered roughly simple-to-rich.
+fn chord_templates() -> &'static [(&'static [u8], &'static str)] {
+    &[
+        (&[0, 7], "5"),
+        (&[0, 4, 8], "aug"),
+        (&[0, 2, 7], "sus2"),
+        (&[0, 5, 7], "sus4"),
+        (&[0, 3, 6], "dim"),
+        (&[0, 3, 7], "m"),
+        (&[0, 4, 7], ""),
+        (&[0, 3, 6, 9], "dim7"),
+        (&[0, 3, 6, 10], "m7b5"),
+        (&[0, 3, 7, 9], "m6"),
+        (&[0, 4, 7, 9], "6"),
+        (&[0, 4, 7, 10], "7"),
+        (&[0, 3, 7, 10], "m7"),
+        (&[0, 4, 7, 11], "maj7"),
+    ]
+}
+
+/// Identify the chord name(s) that match a collection of notes.
+///
+/// Each input note is treated as a candidate root; the remaining notes are
+/// reduced to a set of intervals above it and compared against the known chord
+/// templates. Every `(root, name)` whose template is contained in the played
+/// intervals is returned, ranked so exact matches precede partial ones and
+/// richer chords precede their simpler subsets.
+pub fn identify_chords(notes: &[Note]) -> Vec<(Note, String)> {
+    let mut played: Vec<u8> = notes.iter().map(|n| n.semitone()).collect();
+    played.sort_unstable();
+    played.dedup();
+
+    let mut ranked: Vec<(bool, usize, Note, String)> = Vec::new();
+    let mut seen_roots: Vec<Note> = Vec::new();
+    for &root in notes {
+        if seen_roots.contains(&root) {
+            continue;
+        }
+        seen_roots.push(root);
+
+        let mut intervals: Vec<u8> = played
+            .iter()
+            .map(|&pc| (pc as i32 - root.semitone() as i32).rem_euclid(12) as u8)
+            .collect();
+        intervals.sort_unstable();
+        intervals.dedup();
+
+        for (template, suffix) in chord_templates() {
+            if template.iter().all(|t| intervals.contains(t)) {
+                let exact = intervals.len() == template.len();
+                ranked.push((exact, template.len(), root, format!("{}{}", root, suffix)));
+            }
+        }
+    }
+
+    // Exact matches first, then richer templates, preserving discovery order.
+    ranked.sort_by(|a, b| b.0.cmp(&a.0).then(b.1.cmp(&a.1)));
+
+    let mut result = Vec::new();
+    for (_, _, root, name) in ranked {
+        if !result.iter().any(|(r, n)| *r == root && *n == name) {
+            result.push((root, name));
+        }
+    }
+    result
+}
+
+/// Open-string pitch classes in standard tuning, low E to high E.
+const OPEN_STRINGS: [u8; 6] = [4, 9, 2, 7, 11, 4];
+
+/// Search parameters for the algorithmic voicing generator.
+#[derive(Debug, Clone, Copy)]
+pub struct VoicingConfig {
+    /// Lowest fret the search window starts on.
+    pub min_fret: u8,
+    /// Highest fret any string may be fretted at.
+    pub max_fret: u8,
+    /// Largest allowed distance between the lowest and highest fretted note.
+    pub max_span: u8,
+}
+
+impl Default for VoicingConfig {
+    fn default() -> Self {
+        VoicingConfig {
+            min_fret: 0,
+            max_fret: 15,
+            max_span: 4,
+        }
+    }
+}
+
+impl Chord {
+    /// Pitch classes (0-11) sounded by this chord.
+    fn pitch_classes(&self) -> Vec<u8> {
+        self.intervals
+            .iter()
+            .map(|&iv| (self.root.semitone() + iv % 12) % 12)
+            .collect()
+    }
+
+    /// Chord tones that every voicing must contain: the root, the third, and
+    /// the sixth/seventh when present. The fifth is treated as optional so it
+    /// can be dropped when there aren't enough strings to cover everything.
+    fn required_tones(&self) -> Vec<u8> {
+        let root = self.root.semitone();
+        let mut required = vec![root];
+        for &iv in &self.intervals {
+            match iv % 12 {
+                3 | 4 => required.push((root + iv % 12) % 12),
+                9 | 10 | 11 => required.push((root + iv % 12) % 12),
+                _ => {}
+            }
+        }
+        required.sort_unstable();
+        required.dedup();
+        required
+    }
+
+    /// Generate playable voicings for this chord by searching the fretboard,
+    /// returned best-first (lowest score). See [`VoicingConfig`] for the search
+    /// window. The window `[start, start + max_span]` is swept up the neck from
+    /// `min_fret` to `max_fret`, so the results include open, barre, and
+    /// high-position fingerings for arbitrary roots and extended chords.
+    pub fn voicings(&self, config: VoicingConfig) -> Vec<ChordTab> {
+        let required = self.required_tones();
+        let root_pc = self.root.semitone();
+
+        let mut scored: Vec<(i32, ChordTab)> = Vec::new();
+        for start in config.min_fret..=config.max_fret {
+            let window_top = config.max_fret.min(start + config.max_span);
+            self.collect_window(start, window_top, &required, root_pc, config.max_span, &mut scored);
+        }
+
+        scored.sort_by_key(|(score, _)| *score);
+        scored.dedup_by(|a, b| a.1.fingers == b.1.fingers);
+        scored.into_iter().map(|(_, tab)| tab).collect()
+    }
+
+    /// Enumerate every fingering within a single `[window_bottom, window_top]`
+    /// fret window and append the playable ones to `scored`.
+    fn collect_window(
+        &self,
+        window_bottom: u8,
+        window_top: u8,
+        required: &[u8],
+        root_pc: u8,
+        max_span: u8,
+        scored: &mut Vec<(i32, ChordTab)>,
+    ) {
+        let members = self.pitch_classes();
+
+        // Per-string candidate frets: the "muted" option plus every fret in the
+        // window whose resulting note belongs to the chord.
+        let options: Vec<Vec<i8>> = OPEN_STRINGS
+            .iter()
+            .map(|&open| {
+                let mut opts = vec![-1i8];
+                for fret in window_bottom..=window_top {
+                    if members.contains(&((open + fret) % 12)) {
+                        opts.push(fret as i8);
+                    }
+                }
+                opts
+            })
+            .collect();
+
+        let lengths: Vec<usize> = options.iter().map(|o| o.len()).collect();
+        let mut idx = vec![0usize; 6];
+
+        loop {
+            let fingers: Vec<i8> = (0..6).map(|s| options[s][idx[s]]).collect();
+            if let Some((score, tab)) = score_voicing(&fingers, required, root_pc, max_span) {
+                scored.push((score, tab));
+            }
+
+            // Odometer increment across the six strings.
+            let mut s = 0;
+            loop {
+                idx[s] += 1;
+                if idx[s] < lengths[s] {
+                    break;
+                }
+                idx[s] = 0;
+                s += 1;
+                if s == 6 {
+                    return;
+                }
+            }
+        }
+    }
+
+    /// The single best voicing for this chord, or `None` if the chord cannot be
+    /// fingered within the given window.
+    pub fn best_voicing(&self, config: VoicingConfig) -> Option<ChordTab> {
+        self.voicings(config).into_iter().next()
+    }
+}
+
+/// Score a candidate fingering, rejecting ones that are unplayable or miss a
+/// required chord tone. Lower scores are better.
+fn score_voicing(
+    fingers: &[i8],
+    required: &[u8],
+    root_pc: u8,
+    max_span: u8,
+) -> Option<(i32, ChordTab)> {
+    let sounding: Vec<(usize, i8)> = fingers
+        .iter()
+        .enumerate()
+        .filter(|(_, &f)| f >= 0)
+        .map(|(i, &f)| (i, f))
+        .collect();
+
+    if sounding.is_empty() {
+        return None;
+    }
+
+    // Every required chord tone must sound somewhere in the voicing.
+    let sounded: Vec<u8> = sounding
+        .iter()
+        .map(|&(i, f)| (OPEN_STRINGS[i] + f as u8) % 12)
+        .collect();
+    if !required.iter().all(|pc| sounded.contains(pc)) {
+        return None;
+    }
+
+    // Span is measured across fretted (non-open) strings.
+    let fretted: Vec<i8> = sounding.iter().map(|&(_, f)| f).filter(|&f| f > 0).collect();
+    let span = match (fretted.iter().min(), fretted.iter().max()) {
+        (Some(&lo), Some(&hi)) => (hi - lo) as i32,
+        _ => 0,
+    };
+    if span > max_span as i32 {
+        return None;
+    }
+
+    let height: i32 = fretted.iter().map(|&f| f as i32).sum();
+    let muted = fingers.iter().filter(|&&f| f < 0).count() as i32;
+    let mut score = span + height + muted;
+
+    // Prefer the chord root on the lowest sounding string.
+    let (lowest_string, lowest_fret) = sounding[0];
+    if (OPEN_STRINGS[lowest_string] + lowest_fret as u8) % 12 != root_pc {
+        score += 100;
+    }
+
+    let base_fret = fretted.iter().min().copied().unwrap_or(0) as u8;
+    Some((score, ChordTab::new(String::new(), fingers.to_vec(), base_fret)))
+}
+
 /// Get guitar chord tab for a chord name
 pub fn get_chord_tab(chord_name: &str) -> Option<ChordTab> {
     // Standard guitar chord fingerings
@@ -400,6 +1074,54 @@ pub fn generate_modal_progression(root: Note, mode: Mode) -> Vec<Chord> {
     }
 }
 
+/// Harmonize a single scale degree into its diatonic seventh chord by stacking
+/// scale thirds (degree, degree+2, degree+4, degree+6 within the mode).
+fn diatonic_seventh(scale: &[Note], intervals: &[u8], degree: usize) -> Chord {
+    let stacked = [degree, (degree + 2) % 7, (degree + 4) % 7, (degree + 6) % 7];
+    let base = intervals[degree] as i32;
+    let relative: Vec<u8> = stacked
+        .iter()
+        .map(|&d| (intervals[d] as i32 - base).rem_euclid(12) as u8)
+        .collect();
+    let root = scale[degree];
+
+    match relative.as_slice() {
+        [0, 4, 7, 11] => Chord::major_seventh(root),
+        [0, 3, 7, 10] => Chord::minor_seventh(root),
+        [0, 4, 7, 10] => Chord::dominant_seventh(root),
+        [0, 3, 6, 10] => Chord::half_diminished(root),
+        [0, 3, 6, 9] => Chord::diminished_seventh(root),
+        // Any other stacking (e.g. an augmented-major color) is kept verbatim.
+        _ => Chord::new(root, relative, format!("{}7", root)),
+    }
+}
+
+/// Generate a chord progression for a given mode using diatonic seventh chords.
+///
+/// Uses the same scale-degree motion as [`generate_modal_progression`] but
+/// harmonizes each degree into its correct diatonic seventh, so Ionian yields
+/// Imaj7–IVmaj7–V7 and Dorian/Mixolydian pick up their characteristic minor-
+/// and dominant-seventh colors.
+pub fn generate_modal_progression_7th(root: Note, mode: Mode) -> Vec<Chord> {
+    let scale = mode.scale(root);
+    let intervals = mode.intervals();
+
+    let degrees: [usize; 4] = match mode {
+        Mode::Phrygian => [0, 1, 0, 6],
+        Mode::Dorian => [0, 3, 0, 3],
+        Mode::Lydian => [0, 1, 0, 1],
+        Mode::Mixolydian => [0, 6, 0, 6],
+        Mode::Aeolian => [0, 3, 0, 4],
+        Mode::Ionian => [0, 3, 0, 4],
+        Mode::Locrian => [0, 1, 0, 4],
+    };
+
+    degrees
+        .iter()
+        .map(|&degree| diatonic_seventh(&scale, &intervals, degree))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -439,4 +1161,129 @@ mod tests {
         let notes = chord.notes();
         assert_eq!(notes, vec![Note::A, Note::C, Note::E]);
     }
+
+    #[test]
+    fn test_scale_from_steps() {
+        // The major scale step pattern reproduces Ionian.
+        let scale = Scale::from_steps("WWHWWWH").unwrap();
+        assert_eq!(scale.intervals, Mode::Ionian.intervals());
+        assert_eq!(scale.notes(Note::C), Mode::Ionian.scale(Note::C));
+    }
+
+    #[test]
+    fn test_scale_progression_pentatonic() {
+        // A five-note scale still harmonizes without panicking on wrap-around.
+        let scale = Scale::minor_pentatonic();
+        let progression = generate_scale_progression(Note::A, &scale);
+        assert_eq!(progression.len(), 4);
+        assert_eq!(progression[0].root, Note::A);
+    }
+
+    #[test]
+    fn test_pitch_round_trip() {
+        let pitch = Pitch::from_string("C#4").unwrap();
+        assert_eq!(pitch, Pitch::new(Note::CSharp, 4));
+        assert_eq!(pitch.to_string(), "C#4");
+        assert_eq!(Pitch::new(Note::C, 4).midi_number(), 60);
+    }
+
+    #[test]
+    fn test_pitch_transpose_crosses_octave() {
+        // B4 up a major third rolls into the next octave.
+        assert_eq!(Pitch::new(Note::B, 4).transpose(4), Pitch::new(Note::DSharp, 5));
+    }
+
+    #[test]
+    fn test_voiced_notes_ascend() {
+        let pitches = Chord::major_seventh(Note::B).voiced_notes(3);
+        // The voicing rolls from octave 3 into octave 4: every tone above the
+        // root B3 (up to the seventh, A#4) stays above it instead of wrapping.
+        assert_eq!(
+            pitches,
+            vec![
+                Pitch::new(Note::B, 3),
+                Pitch::new(Note::DSharp, 4),
+                Pitch::new(Note::FSharp, 4),
+                Pitch::new(Note::ASharp, 4),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_spell_scale_flat_key() {
+        let spelled: Vec<String> = spell_scale(Note::F, Mode::Ionian)
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(spelled, vec!["F", "G", "A", "Bb", "C", "D", "E"]);
+    }
+
+    #[test]
+    fn test_spell_scale_sharp_key() {
+        let spelled: Vec<String> = spell_scale(Note::C, Mode::Lydian)
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        // Lydian's raised fourth spells as F#, and every letter appears once.
+        assert_eq!(spelled, vec!["C", "D", "E", "F#", "G", "A", "B"]);
+    }
+
+    #[test]
+    fn test_spelled_notes_minor_and_diminished() {
+        // A minor third spells as a flatted third letter, not a sharp second.
+        let cm: Vec<String> = Chord::minor(Note::C)
+            .spelled_notes()
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(cm, vec!["C", "Eb", "G"]);
+
+        // Diminished stacks a flat third and flat fifth — C Eb Gb, not C D# F#.
+        let cdim: Vec<String> = Chord::diminished(Note::C)
+            .spelled_notes()
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(cdim, vec!["C", "Eb", "Gb"]);
+    }
+
+    #[test]
+    fn test_identify_chords() {
+        // C E G is an exact C major triad.
+        let matches = identify_chords(&[Note::C, Note::E, Note::G]);
+        assert_eq!(matches.first(), Some(&(Note::C, "C".to_string())));
+
+        // C E G B is a Cmaj7; the richer template should rank ahead of plain C.
+        let matches = identify_chords(&[Note::C, Note::E, Note::G, Note::B]);
+        assert_eq!(matches.first(), Some(&(Note::C, "Cmaj7".to_string())));
+        assert!(matches.contains(&(Note::C, "C".to_string())));
+    }
+
+    #[test]
+    fn test_algorithmic_voicing_covers_seventh_chord() {
+        // The static table has no Cmaj7, so this exercises the fretboard search.
+        let chord = Chord::major_seventh(Note::C);
+        let tab = chord.get_guitar_tab().expect("should find a voicing");
+        assert_eq!(tab.name, "Cmaj7");
+
+        // Every required tone (root, third, seventh) must sound in the voicing.
+        let sounded: Vec<u8> = tab
+            .fingers
+            .iter()
+            .enumerate()
+            .filter(|(_, &f)| f >= 0)
+            .map(|(i, &f)| (OPEN_STRINGS[i] + f as u8) % 12)
+            .collect();
+        for tone in chord.required_tones() {
+            assert!(sounded.contains(&tone), "missing chord tone {}", tone);
+        }
+    }
+
+    #[test]
+    fn test_diatonic_seventh_progression() {
+        let progression = generate_modal_progression_7th(Note::C, Mode::Ionian);
+        let names: Vec<&str> = progression.iter().map(|c| c.name.as_str()).collect();
+        // Ionian harmonizes to Imaj7 – IVmaj7 – Imaj7 – V7.
+        assert_eq!(names, vec!["Cmaj7", "Fmaj7", "Cmaj7", "G7"]);
+    }
 }